@@ -0,0 +1,271 @@
+//! Generates Rust struct source matching a `Schema`'s shape, so callers get
+//! a type-safe struct to deserialize into without hand-copying the schema
+//! into a separate `struct` definition that can drift out of sync.
+
+use crate::schema::{FieldDefinition, Schema};
+use crate::types::FieldType;
+
+impl Schema {
+    /// Generates `#[derive(Serialize, Deserialize)]` Rust struct source for
+    /// this schema: `nullable` fields become `Option<T>`, and non-`required`
+    /// fields get `#[serde(default)]` so they can be omitted from the JSON
+    /// being deserialized. Field order is sorted by name for deterministic
+    /// output, since `Schema::fields` is a `HashMap`.
+    pub fn to_rust_struct(&self) -> String {
+        let struct_name = to_pascal_case(&self.name);
+        let mut field_names: Vec<&String> = self.fields.keys().collect();
+        field_names.sort();
+
+        let mut source = String::new();
+        source.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+        source.push_str(&format!("pub struct {struct_name} {{\n"));
+        for field_name in field_names {
+            let definition = &self.fields[field_name];
+            let ident = to_rust_ident(field_name);
+            // The field's wire name no longer matches its Rust identifier
+            // once sanitized (e.g. `type` -> `r#type`), so pin it back down
+            // with `serde(rename)` to keep (de)serializing the same JSON key.
+            if ident != *field_name {
+                source.push_str(&format!("    #[serde(rename = \"{field_name}\")]\n"));
+            }
+            if !definition.required {
+                source.push_str("    #[serde(default)]\n");
+            }
+            source.push_str(&format!(
+                "    pub {ident}: {},\n",
+                rust_type_for(definition)
+            ));
+        }
+        source.push_str("}\n");
+        source
+    }
+}
+
+/// The Rust type for a field's value, wrapped in `Option<T>` when the field
+/// is `nullable`. `required` is not reflected in the type (the caller adds
+/// `#[serde(default)]` for it).
+fn rust_type_for(definition: &FieldDefinition) -> String {
+    let base = match &definition.field_type {
+        FieldType::String => "String".to_string(),
+        FieldType::Integer => "i64".to_string(),
+        FieldType::Float => "f64".to_string(),
+        FieldType::Boolean => "bool".to_string(),
+        // Format (e.g. iso8601 vs unix_timestamp) is a `ValidationRule`, not
+        // part of the type, so a datetime field is just its wire string.
+        FieldType::DateTime => "String".to_string(),
+        FieldType::Object => "serde_json::Value".to_string(),
+        FieldType::Array => match &definition.items {
+            Some(items) => format!("Vec<{}>", rust_type_for(items)),
+            None => "Vec<serde_json::Value>".to_string(),
+        },
+        FieldType::Any => "serde_json::Value".to_string(),
+    };
+    if definition.nullable {
+        format!("Option<{base}>")
+    } else {
+        base
+    }
+}
+
+/// A handful of keywords (`self`, `super`, `Self`, `crate`) are reserved
+/// even as a raw identifier (`r#self` does not compile), so they need a
+/// different escape than the rest.
+const RAW_IDENT_INCOMPATIBLE_KEYWORDS: [&str; 4] = ["self", "super", "Self", "crate"];
+
+/// Turns a schema field name into a valid Rust identifier: non-identifier
+/// characters become `_`, a leading digit gets a `_` prefix, and a
+/// keyword is escaped as a raw identifier (`r#type`) or, for the handful
+/// that can't be (`self`/`super`/`Self`/`crate`), given a trailing `_`.
+fn to_rust_ident(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if ident.is_empty() || ident.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+
+    if RAW_IDENT_INCOMPATIBLE_KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+        ident
+    } else if is_rust_keyword(&ident) {
+        format!("r#{ident}")
+    } else {
+        ident
+    }
+}
+
+fn is_rust_keyword(ident: &str) -> bool {
+    matches!(
+        ident,
+        "as" | "break"
+            | "const"
+            | "continue"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "static"
+            | "struct"
+            | "trait"
+            | "true"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+            | "dyn"
+            | "async"
+            | "await"
+            | "abstract"
+            | "become"
+            | "box"
+            | "do"
+            | "final"
+            | "macro"
+            | "override"
+            | "priv"
+            | "try"
+            | "typeof"
+            | "union"
+            | "unsized"
+            | "virtual"
+            | "yield"
+    )
+}
+
+/// Converts a `snake_case` or `kebab-case` schema name into `PascalCase`.
+fn to_pascal_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::FieldDefinition;
+    use crate::types::ValidationRule;
+
+    fn user_schema() -> Schema {
+        Schema::new("user")
+            .field(
+                "user_id",
+                FieldDefinition::new(FieldType::Integer).required(),
+            )
+            .field(
+                "email",
+                FieldDefinition::new(FieldType::String)
+                    .required()
+                    .rule(ValidationRule::Pattern(r"^[^@]+@[^@]+$".to_string())),
+            )
+            .field("age", FieldDefinition::new(FieldType::Integer).nullable())
+    }
+
+    #[test]
+    fn struct_name_is_pascal_cased_from_the_schema_name() {
+        let source = user_schema().to_rust_struct();
+        assert!(source.contains("pub struct User {"));
+    }
+
+    #[test]
+    fn required_field_has_no_default_attribute() {
+        let source = user_schema().to_rust_struct();
+        assert!(source.contains("pub user_id: i64,"));
+        assert!(!source.contains("#[serde(default)]\n    pub user_id"));
+    }
+
+    #[test]
+    fn nullable_field_is_wrapped_in_option_and_defaulted() {
+        let source = user_schema().to_rust_struct();
+        assert!(source.contains("#[serde(default)]\n    pub age: Option<i64>,"));
+    }
+
+    #[test]
+    fn generated_struct_matches_expected_source_exactly() {
+        let expected = "\
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    #[serde(default)]
+    pub age: Option<i64>,
+    pub email: String,
+    pub user_id: i64,
+}
+";
+        assert_eq!(user_schema().to_rust_struct(), expected);
+    }
+
+    #[test]
+    fn array_field_without_items_maps_to_vec_of_json_value() {
+        let schema = Schema::new("event").field(
+            "tags",
+            FieldDefinition::new(FieldType::Array).required(),
+        );
+        let source = schema.to_rust_struct();
+        assert!(source.contains("pub tags: Vec<serde_json::Value>,"));
+    }
+
+    #[test]
+    fn keyword_field_name_is_escaped_as_a_raw_identifier() {
+        let schema = Schema::new("event").field(
+            "type",
+            FieldDefinition::new(FieldType::String).required(),
+        );
+        let source = schema.to_rust_struct();
+        assert!(source.contains("#[serde(rename = \"type\")]\n    pub r#type: String,"));
+    }
+
+    #[test]
+    fn self_keyword_field_name_gets_a_trailing_underscore() {
+        // `r#self` doesn't compile, unlike most other raw identifiers.
+        let schema = Schema::new("event").field(
+            "self",
+            FieldDefinition::new(FieldType::String).required(),
+        );
+        let source = schema.to_rust_struct();
+        assert!(source.contains("#[serde(rename = \"self\")]\n    pub self_: String,"));
+    }
+
+    #[test]
+    fn hyphenated_field_name_is_sanitized_into_a_valid_identifier() {
+        let schema = Schema::new("event").field(
+            "user-id",
+            FieldDefinition::new(FieldType::Integer).required(),
+        );
+        let source = schema.to_rust_struct();
+        assert!(source.contains("#[serde(rename = \"user-id\")]\n    pub user_id: i64,"));
+    }
+
+    #[test]
+    fn array_field_with_items_maps_to_vec_of_the_item_type() {
+        let schema = Schema::new("event").field(
+            "scores",
+            FieldDefinition::new(FieldType::Array)
+                .required()
+                .items(FieldDefinition::new(FieldType::Float)),
+        );
+        let source = schema.to_rust_struct();
+        assert!(source.contains("pub scores: Vec<f64>,"));
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::error::{CompileError, ValidationError};
+use crate::schema::{FieldDefinition, Schema};
+use crate::types::ValidationRule;
+use crate::validate::validate_with_patterns;
+
+/// A `Schema` with all `Pattern` rules pre-compiled. Build one with
+/// `Schema::compile` and reuse it across calls instead of paying the cost
+/// of recompiling the same regexes on every `validate`.
+#[derive(Debug, Clone)]
+pub struct CompiledSchema {
+    schema: Schema,
+    patterns: HashMap<String, Regex>,
+}
+
+impl CompiledSchema {
+    /// Validates `payload` against the compiled schema, returning the
+    /// normalized payload like the free-function `validate`, but without
+    /// recompiling any `Pattern` regex.
+    pub fn validate(&self, payload: &Value) -> Result<Value, Vec<ValidationError>> {
+        validate_with_patterns(&self.schema, payload, Some(&self.patterns))
+    }
+}
+
+impl Schema {
+    /// Compiles this schema into a `CompiledSchema`, pre-building every
+    /// `Pattern` rule's regex up front. Returns a `CompileError` for the
+    /// first invalid pattern instead of silently ignoring it at validation
+    /// time.
+    pub fn compile(&self) -> Result<CompiledSchema, CompileError> {
+        let mut patterns = HashMap::new();
+        collect_schema_patterns(self, &mut patterns)?;
+        Ok(CompiledSchema {
+            schema: self.clone(),
+            patterns,
+        })
+    }
+}
+
+fn collect_schema_patterns(
+    schema: &Schema,
+    patterns: &mut HashMap<String, Regex>,
+) -> Result<(), CompileError> {
+    for definition in schema.fields.values() {
+        collect_field_patterns(definition, patterns)?;
+    }
+    Ok(())
+}
+
+fn collect_field_patterns(
+    definition: &FieldDefinition,
+    patterns: &mut HashMap<String, Regex>,
+) -> Result<(), CompileError> {
+    for rule in &definition.rules {
+        collect_rule_patterns(rule, patterns)?;
+    }
+    if let Some(properties) = &definition.properties {
+        collect_schema_patterns(properties, patterns)?;
+    }
+    if let Some(items) = &definition.items {
+        collect_field_patterns(items, patterns)?;
+    }
+    Ok(())
+}
+
+fn collect_rule_patterns(
+    rule: &ValidationRule,
+    patterns: &mut HashMap<String, Regex>,
+) -> Result<(), CompileError> {
+    match rule {
+        ValidationRule::Pattern(pattern) if !patterns.contains_key(pattern) => {
+            let re = Regex::new(pattern).map_err(|err| CompileError::InvalidPattern {
+                pattern: pattern.clone(),
+                message: err.to_string(),
+            })?;
+            patterns.insert(pattern.clone(), re);
+        }
+        ValidationRule::AllOf(rules) | ValidationRule::AnyOf(rules) => {
+            for rule in rules {
+                collect_rule_patterns(rule, patterns)?;
+            }
+        }
+        ValidationRule::Not(inner) => collect_rule_patterns(inner, patterns)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::FieldDefinition;
+    use crate::types::FieldType;
+    use serde_json::json;
+
+    fn user_schema() -> Schema {
+        Schema::new("user").field(
+            "email",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::Pattern(r"^[^@]+@[^@]+$".to_string())),
+        )
+    }
+
+    #[test]
+    fn compiles_valid_schema() {
+        assert!(user_schema().compile().is_ok());
+    }
+
+    #[test]
+    fn invalid_pattern_fails_to_compile() {
+        let schema = Schema::new("user").field(
+            "email",
+            FieldDefinition::new(FieldType::String).rule(ValidationRule::Pattern("(".to_string())),
+        );
+        assert!(matches!(
+            schema.compile(),
+            Err(CompileError::InvalidPattern { .. })
+        ));
+    }
+
+    #[test]
+    fn compiled_schema_validates_like_the_free_function() {
+        let compiled = user_schema().compile().unwrap();
+        assert!(compiled.validate(&json!({ "email": "carlos@example.com" })).is_ok());
+        assert!(compiled.validate(&json!({ "email": "not-an-email" })).is_err());
+    }
+}
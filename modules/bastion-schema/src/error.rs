@@ -3,6 +3,9 @@ use thiserror::Error;
 use crate::types::{FieldType, ValidationRule};
 
 #[derive(Debug, Clone, PartialEq, Error)]
+// `field` carries a JSON-pointer-style path (e.g. "address/zip" or "tags/2")
+// rather than a bare field name, so errors produced while recursing into
+// nested objects/arrays stay unambiguous.
 pub enum ValidationError {
     /// A required field is missing from the payload.
     #[error("field '{field}' is required but missing")]
@@ -27,6 +30,19 @@ pub enum ValidationError {
     /// A null value was received for a non-nullable field.
     #[error("field '{field}' is not nullable but received null")]
     NullValue { field: String },
+
+    /// A payload key is not declared in the schema's `fields`, and the
+    /// schema's `unknown_fields` policy is `Reject`.
+    #[error("field '{field}' is not declared in the schema")]
+    UnknownField { field: String },
+}
+
+/// An error produced while compiling a `Schema` into a `CompiledSchema`.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CompileError {
+    /// A `Pattern` rule's regex failed to compile.
+    #[error("invalid pattern '{pattern}': {message}")]
+    InvalidPattern { pattern: String, message: String },
 }
 
 #[cfg(test)]
@@ -76,4 +92,27 @@ mod tests {
         assert!(err.to_string().contains("email"));
         assert!(err.to_string().contains("failed rule"));
     }
+
+    #[test]
+    fn unknown_field_error_message() {
+        let err = ValidationError::UnknownField {
+            field: "nickname".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "field 'nickname' is not declared in the schema"
+        );
+    }
+
+    #[test]
+    fn invalid_pattern_error_message() {
+        let err = CompileError::InvalidPattern {
+            pattern: "(".to_string(),
+            message: "unclosed group".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid pattern '(': unclosed group"
+        );
+    }
 }
@@ -0,0 +1,154 @@
+//! Bootstraps a `Schema` by observing example payloads, instead of
+//! hand-writing every `FieldDefinition`.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+
+use crate::schema::{FieldDefinition, Schema};
+use crate::types::FieldType;
+
+impl Schema {
+    /// Builds a schema from example payloads. For each field observed
+    /// across `samples`:
+    /// - it is marked `required()` only if it appeared in every sample;
+    /// - it is marked `nullable()` if any sample had an explicit JSON
+    ///   `null` for it (tracked independently of presence, so an
+    ///   always-present, never-null field like `id` stays non-nullable);
+    /// - its `FieldType` is the unification of every concrete type seen,
+    ///   widening `Integer` + `Float` to `Float`, and falling back to the
+    ///   permissive `Any` type on irreconcilable conflicts.
+    ///
+    /// Non-object samples are ignored, since a `Schema` only describes the
+    /// fields of an object.
+    pub fn infer(name: impl Into<String>, samples: &[Value]) -> Schema {
+        let mut presence: HashMap<&str, usize> = HashMap::new();
+        let mut nullable: HashMap<&str, bool> = HashMap::new();
+        let mut observed_types: HashMap<&str, HashSet<FieldType>> = HashMap::new();
+        let mut sample_count = 0;
+
+        for sample in samples {
+            let Some(object) = sample.as_object() else {
+                continue;
+            };
+            sample_count += 1;
+
+            for (field_name, value) in object {
+                let field_name = field_name.as_str();
+                *presence.entry(field_name).or_insert(0) += 1;
+                nullable.entry(field_name).or_insert(false);
+                if value.is_null() {
+                    nullable.insert(field_name, true);
+                } else {
+                    observed_types
+                        .entry(field_name)
+                        .or_default()
+                        .insert(observed_type(value));
+                }
+            }
+        }
+
+        let mut schema = Schema::new(name);
+        for (field_name, seen_count) in &presence {
+            let field_type = unify_types(observed_types.remove(field_name).unwrap_or_default());
+            let mut definition = FieldDefinition::new(field_type);
+            if *seen_count == sample_count {
+                definition = definition.required();
+            }
+            if nullable.get(field_name).copied().unwrap_or(false) {
+                definition = definition.nullable();
+            }
+            schema = schema.field(*field_name, definition);
+        }
+        schema
+    }
+}
+
+fn observed_type(value: &Value) -> FieldType {
+    match value {
+        Value::String(_) => FieldType::String,
+        Value::Number(n) if n.is_f64() => FieldType::Float,
+        Value::Number(_) => FieldType::Integer,
+        Value::Bool(_) => FieldType::Boolean,
+        Value::Array(_) => FieldType::Array,
+        Value::Object(_) => FieldType::Object,
+        Value::Null => FieldType::Any, // null values are tracked via `nullable`, not as a type
+    }
+}
+
+/// Unifies the set of concrete types observed for a field into a single
+/// `FieldType`, widening `Integer`/`Float` mixes to `Float` and falling
+/// back to `Any` for any other conflict (or when a field was only ever
+/// seen as `null`).
+fn unify_types(types: HashSet<FieldType>) -> FieldType {
+    let mut types: Vec<FieldType> = types.into_iter().collect();
+    match types.len() {
+        0 => FieldType::Any,
+        1 => types.remove(0),
+        _ if types.iter().all(|t| matches!(t, FieldType::Integer | FieldType::Float)) => {
+            FieldType::Float
+        }
+        _ => FieldType::Any,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn field_present_in_every_sample_is_required() {
+        let schema = Schema::infer(
+            "event",
+            &[json!({ "id": 1 }), json!({ "id": 2 })],
+        );
+        assert!(schema.fields["id"].required);
+    }
+
+    #[test]
+    fn field_absent_from_a_sample_is_not_required() {
+        let schema = Schema::infer(
+            "event",
+            &[json!({ "id": 1, "name": "a" }), json!({ "id": 2 })],
+        );
+        assert!(!schema.fields["name"].required);
+    }
+
+    #[test]
+    fn explicit_null_marks_the_field_nullable() {
+        let schema = Schema::infer(
+            "event",
+            &[json!({ "deleted_at": null }), json!({ "deleted_at": "2024-01-01T00:00:00Z" })],
+        );
+        assert!(schema.fields["deleted_at"].nullable);
+    }
+
+    #[test]
+    fn always_present_never_null_field_stays_non_nullable() {
+        // Regression: presence and nullability must be tracked independently,
+        // so a field that is present in every sample and null in none is
+        // marked required but not nullable.
+        let schema = Schema::infer("event", &[json!({ "id": 1 }), json!({ "id": 2 })]);
+        assert!(!schema.fields["id"].nullable);
+        assert!(schema.fields["id"].required);
+    }
+
+    #[test]
+    fn integer_and_float_samples_widen_to_float() {
+        let schema = Schema::infer(
+            "event",
+            &[json!({ "amount": 1 }), json!({ "amount": 1.5 })],
+        );
+        assert_eq!(schema.fields["amount"].field_type, FieldType::Float);
+    }
+
+    #[test]
+    fn irreconcilable_type_conflict_falls_back_to_any() {
+        let schema = Schema::infer(
+            "event",
+            &[json!({ "value": 1 }), json!({ "value": "one" })],
+        );
+        assert_eq!(schema.fields["value"].field_type, FieldType::Any);
+    }
+}
@@ -0,0 +1,505 @@
+//! Bidirectional conversion between a `Schema` and a JSON Schema draft-7
+//! document, so users can interoperate with the wider JSON Schema ecosystem
+//! (editors, other validators) instead of being locked into this crate's
+//! native `Serialize` form.
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::schema::{FieldDefinition, Schema};
+use crate::types::{DateTimeFormat, FieldType, IpKind, ValidationRule};
+
+/// An error produced while importing a JSON Schema draft-7 document.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum JsonSchemaError {
+    /// The document (or a nested `properties` entry) is not a JSON object.
+    #[error("expected a JSON object")]
+    NotAnObject,
+    /// A field schema's `"type"` array names no type other than `"null"`.
+    /// (A field schema with no `"type"` keyword at all imports as `Any`.)
+    #[error("field '{field}' has no non-null \"type\"")]
+    MissingType { field: String },
+    /// A field schema's `"type"` is not one this crate can represent.
+    #[error("field '{field}' has an unsupported type '{type_name}'")]
+    UnsupportedType { field: String, type_name: String },
+}
+
+impl Schema {
+    /// Exports this schema as a JSON Schema draft-7 document.
+    pub fn to_json_schema(&self) -> Value {
+        let mut properties = Map::new();
+        let mut required = vec![];
+        for (name, definition) in &self.fields {
+            properties.insert(name.clone(), field_to_json_schema(definition));
+            if definition.required {
+                required.push(Value::String(name.clone()));
+            }
+        }
+
+        let mut root = Map::new();
+        root.insert(
+            "$schema".to_string(),
+            Value::String("http://json-schema.org/draft-07/schema#".to_string()),
+        );
+        root.insert("title".to_string(), Value::String(self.name.clone()));
+        root.insert("type".to_string(), Value::String("object".to_string()));
+        root.insert("properties".to_string(), Value::Object(properties));
+        if !required.is_empty() {
+            root.insert("required".to_string(), Value::Array(required));
+        }
+        Value::Object(root)
+    }
+
+    /// Imports a JSON Schema draft-7 document into a `Schema`.
+    pub fn from_json_schema(document: &Value) -> Result<Schema, JsonSchemaError> {
+        let root = document.as_object().ok_or(JsonSchemaError::NotAnObject)?;
+
+        let name = root
+            .get("title")
+            .and_then(Value::as_str)
+            .unwrap_or("schema")
+            .to_string();
+
+        let required: Vec<&str> = root
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|values| values.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let properties = root
+            .get("properties")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut schema = Schema::new(name);
+        for (field_name, field_schema) in properties {
+            let is_required = required.contains(&field_name.as_str());
+            let definition = field_from_json_schema(&field_name, &field_schema, is_required)?;
+            schema = schema.field(field_name, definition);
+        }
+        Ok(schema)
+    }
+}
+
+fn field_to_json_schema(definition: &FieldDefinition) -> Value {
+    let mut obj = Map::new();
+
+    // `Any` has no draft-7 keyword: omitting "type" is how JSON Schema
+    // expresses "matches anything".
+    if let Some(keyword) = field_type_keyword(&definition.field_type) {
+        let base_type = Value::String(keyword.to_string());
+        if definition.nullable {
+            obj.insert("type".to_string(), Value::Array(vec![base_type, Value::String("null".to_string())]));
+        } else {
+            obj.insert("type".to_string(), base_type);
+        }
+    }
+
+    if definition.field_type == FieldType::Object {
+        if let Some(properties) = &definition.properties {
+            if let Value::Object(nested) = properties.to_json_schema() {
+                if let Some(props) = nested.get("properties") {
+                    obj.insert("properties".to_string(), props.clone());
+                }
+                if let Some(req) = nested.get("required") {
+                    obj.insert("required".to_string(), req.clone());
+                }
+            }
+        }
+    }
+
+    if definition.field_type == FieldType::Array {
+        if let Some(items) = &definition.items {
+            obj.insert("items".to_string(), field_to_json_schema(items));
+        }
+    }
+
+    for rule in &definition.rules {
+        apply_rule_keywords(rule, &mut obj);
+    }
+
+    Value::Object(obj)
+}
+
+fn field_type_keyword(field_type: &FieldType) -> Option<&'static str> {
+    match field_type {
+        FieldType::String => Some("string"),
+        FieldType::Integer => Some("integer"),
+        FieldType::Float => Some("number"),
+        FieldType::Boolean => Some("boolean"),
+        FieldType::DateTime => Some("string"),
+        FieldType::Object => Some("object"),
+        FieldType::Array => Some("array"),
+        FieldType::Any => None,
+    }
+}
+
+fn apply_rule_keywords(rule: &ValidationRule, obj: &mut Map<String, Value>) {
+    match rule {
+        ValidationRule::Pattern(pattern) => {
+            obj.insert("pattern".to_string(), Value::String(pattern.clone()));
+        }
+        ValidationRule::MinLength(min) => {
+            obj.insert("minLength".to_string(), Value::from(*min));
+        }
+        ValidationRule::MaxLength(max) => {
+            obj.insert("maxLength".to_string(), Value::from(*max));
+        }
+        ValidationRule::MinValue(min) => {
+            obj.insert("minimum".to_string(), Value::from(*min));
+        }
+        ValidationRule::MaxValue(max) => {
+            obj.insert("maximum".to_string(), Value::from(*max));
+        }
+        ValidationRule::ExclusiveMinValue(min) => {
+            obj.insert("exclusiveMinimum".to_string(), Value::from(*min));
+        }
+        ValidationRule::ExclusiveMaxValue(max) => {
+            obj.insert("exclusiveMaximum".to_string(), Value::from(*max));
+        }
+        ValidationRule::OneOf(values) => {
+            obj.insert("enum".to_string(), Value::Array(values.clone()));
+        }
+        ValidationRule::DateTimeFormat(DateTimeFormat::Iso8601) => {
+            obj.insert("format".to_string(), Value::String("date-time".to_string()));
+        }
+        ValidationRule::DateTimeFormat(DateTimeFormat::UnixTimestamp) => {
+            obj.insert("format".to_string(), Value::String("unix-time".to_string()));
+        }
+        ValidationRule::Email => {
+            obj.insert("format".to_string(), Value::String("email".to_string()));
+        }
+        ValidationRule::Url => {
+            obj.insert("format".to_string(), Value::String("uri".to_string()));
+        }
+        ValidationRule::Uuid => {
+            obj.insert("format".to_string(), Value::String("uuid".to_string()));
+        }
+        ValidationRule::CreditCard => {
+            obj.insert("format".to_string(), Value::String("credit-card".to_string()));
+        }
+        ValidationRule::Ip(IpKind::V4) => {
+            obj.insert("format".to_string(), Value::String("ipv4".to_string()));
+        }
+        ValidationRule::Ip(IpKind::V6) => {
+            obj.insert("format".to_string(), Value::String("ipv6".to_string()));
+        }
+        ValidationRule::Ip(IpKind::Either) => {
+            obj.insert("format".to_string(), Value::String("ip-address".to_string()));
+        }
+        ValidationRule::AllOf(rules) => {
+            obj.insert("allOf".to_string(), Value::Array(rules.iter().map(rule_as_schema).collect()));
+        }
+        ValidationRule::AnyOf(rules) => {
+            obj.insert("anyOf".to_string(), Value::Array(rules.iter().map(rule_as_schema).collect()));
+        }
+        ValidationRule::Not(inner) => {
+            obj.insert("not".to_string(), rule_as_schema(inner));
+        }
+    }
+}
+
+fn rule_as_schema(rule: &ValidationRule) -> Value {
+    let mut obj = Map::new();
+    apply_rule_keywords(rule, &mut obj);
+    Value::Object(obj)
+}
+
+fn field_from_json_schema(
+    field_name: &str,
+    field_schema: &Value,
+    required: bool,
+) -> Result<FieldDefinition, JsonSchemaError> {
+    let obj = field_schema.as_object().ok_or(JsonSchemaError::NotAnObject)?;
+    let (mut field_type, nullable) = parse_type(field_name, obj)?;
+
+    // Draft-7 has no dedicated "datetime" type keyword — `DateTime` exports
+    // as a plain `"string"` with a `date-time`/`unix-time` `format`, so a
+    // `"string"` carrying one of those formats round-trips back to
+    // `DateTime` rather than staying `String`.
+    if field_type == FieldType::String
+        && matches!(
+            obj.get("format").and_then(Value::as_str).and_then(format_to_rule),
+            Some(ValidationRule::DateTimeFormat(_))
+        )
+    {
+        field_type = FieldType::DateTime;
+    }
+
+    let mut definition = FieldDefinition::new(field_type.clone());
+    if required {
+        definition = definition.required();
+    }
+    if nullable {
+        definition = definition.nullable();
+    }
+
+    for rule in extract_rules(obj) {
+        definition = definition.rule(rule);
+    }
+
+    if field_type == FieldType::Object {
+        if let Some(properties) = obj.get("properties") {
+            let mut nested_root = Map::new();
+            nested_root.insert("properties".to_string(), properties.clone());
+            if let Some(req) = obj.get("required") {
+                nested_root.insert("required".to_string(), req.clone());
+            }
+            let nested_schema = Schema::from_json_schema(&Value::Object(nested_root))?;
+            definition = definition.properties(nested_schema);
+        }
+    }
+
+    if field_type == FieldType::Array {
+        if let Some(items) = obj.get("items") {
+            let item_definition = field_from_json_schema(field_name, items, false)?;
+            definition = definition.items(item_definition);
+        }
+    }
+
+    Ok(definition)
+}
+
+/// Reads every `ValidationRule`-bearing keyword out of a field schema
+/// object, including `allOf`/`anyOf`/`not`, whose sub-schemas are parsed
+/// recursively via `rule_from_sub_schema`.
+fn extract_rules(obj: &Map<String, Value>) -> Vec<ValidationRule> {
+    let mut rules = vec![];
+    if let Some(pattern) = obj.get("pattern").and_then(Value::as_str) {
+        rules.push(ValidationRule::Pattern(pattern.to_string()));
+    }
+    if let Some(min) = obj.get("minLength").and_then(Value::as_u64) {
+        rules.push(ValidationRule::MinLength(min as usize));
+    }
+    if let Some(max) = obj.get("maxLength").and_then(Value::as_u64) {
+        rules.push(ValidationRule::MaxLength(max as usize));
+    }
+    if let Some(min) = obj.get("minimum").and_then(Value::as_f64) {
+        rules.push(ValidationRule::MinValue(min));
+    }
+    if let Some(max) = obj.get("maximum").and_then(Value::as_f64) {
+        rules.push(ValidationRule::MaxValue(max));
+    }
+    if let Some(min) = obj.get("exclusiveMinimum").and_then(Value::as_f64) {
+        rules.push(ValidationRule::ExclusiveMinValue(min));
+    }
+    if let Some(max) = obj.get("exclusiveMaximum").and_then(Value::as_f64) {
+        rules.push(ValidationRule::ExclusiveMaxValue(max));
+    }
+    if let Some(values) = obj.get("enum").and_then(Value::as_array) {
+        rules.push(ValidationRule::OneOf(values.clone()));
+    }
+    if let Some(format) = obj.get("format").and_then(Value::as_str) {
+        if let Some(rule) = format_to_rule(format) {
+            rules.push(rule);
+        }
+    }
+    if let Some(schemas) = obj.get("allOf").and_then(Value::as_array) {
+        let nested: Vec<ValidationRule> = schemas.iter().filter_map(rule_from_sub_schema).collect();
+        if !nested.is_empty() {
+            rules.push(ValidationRule::AllOf(nested));
+        }
+    }
+    if let Some(schemas) = obj.get("anyOf").and_then(Value::as_array) {
+        let nested: Vec<ValidationRule> = schemas.iter().filter_map(rule_from_sub_schema).collect();
+        if !nested.is_empty() {
+            rules.push(ValidationRule::AnyOf(nested));
+        }
+    }
+    if let Some(not_schema) = obj.get("not") {
+        if let Some(inner) = rule_from_sub_schema(not_schema) {
+            rules.push(ValidationRule::Not(Box::new(inner)));
+        }
+    }
+    rules
+}
+
+/// Parses a combinator's nested sub-schema (as produced by `rule_as_schema`)
+/// back into the single `ValidationRule` it encodes.
+fn rule_from_sub_schema(value: &Value) -> Option<ValidationRule> {
+    let obj = value.as_object()?;
+    extract_rules(obj).into_iter().next()
+}
+
+fn parse_type(
+    field_name: &str,
+    obj: &Map<String, Value>,
+) -> Result<(FieldType, bool), JsonSchemaError> {
+    // A missing "type" keyword is valid JSON Schema, meaning "matches
+    // anything" — the same thing `FieldType::Any` represents.
+    let Some(type_value) = obj.get("type") else {
+        return Ok((FieldType::Any, false));
+    };
+
+    match type_value {
+        Value::String(keyword) => Ok((keyword_to_field_type(field_name, keyword)?, false)),
+        Value::Array(keywords) => {
+            let mut nullable = false;
+            let mut field_type = None;
+            for keyword in keywords {
+                let keyword = keyword.as_str().ok_or_else(|| JsonSchemaError::UnsupportedType {
+                    field: field_name.to_string(),
+                    type_name: keyword.to_string(),
+                })?;
+                if keyword == "null" {
+                    nullable = true;
+                } else {
+                    field_type = Some(keyword_to_field_type(field_name, keyword)?);
+                }
+            }
+            let field_type = field_type
+                .ok_or_else(|| JsonSchemaError::MissingType { field: field_name.to_string() })?;
+            Ok((field_type, nullable))
+        }
+        other => Err(JsonSchemaError::UnsupportedType {
+            field: field_name.to_string(),
+            type_name: other.to_string(),
+        }),
+    }
+}
+
+fn keyword_to_field_type(field_name: &str, keyword: &str) -> Result<FieldType, JsonSchemaError> {
+    match keyword {
+        "string" => Ok(FieldType::String),
+        "integer" => Ok(FieldType::Integer),
+        "number" => Ok(FieldType::Float),
+        "boolean" => Ok(FieldType::Boolean),
+        "object" => Ok(FieldType::Object),
+        "array" => Ok(FieldType::Array),
+        other => Err(JsonSchemaError::UnsupportedType {
+            field: field_name.to_string(),
+            type_name: other.to_string(),
+        }),
+    }
+}
+
+fn format_to_rule(format: &str) -> Option<ValidationRule> {
+    match format {
+        "date-time" => Some(ValidationRule::DateTimeFormat(DateTimeFormat::Iso8601)),
+        "unix-time" => Some(ValidationRule::DateTimeFormat(DateTimeFormat::UnixTimestamp)),
+        "email" => Some(ValidationRule::Email),
+        "uri" => Some(ValidationRule::Url),
+        "uuid" => Some(ValidationRule::Uuid),
+        "credit-card" => Some(ValidationRule::CreditCard),
+        "ipv4" => Some(ValidationRule::Ip(IpKind::V4)),
+        "ipv6" => Some(ValidationRule::Ip(IpKind::V6)),
+        "ip-address" => Some(ValidationRule::Ip(IpKind::Either)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn user_schema() -> Schema {
+        Schema::new("user")
+            .field(
+                "user_id",
+                FieldDefinition::new(FieldType::Integer).required(),
+            )
+            .field(
+                "email",
+                FieldDefinition::new(FieldType::String)
+                    .required()
+                    .rule(ValidationRule::Pattern(r"^[^@]+@[^@]+$".to_string())),
+            )
+            .field(
+                "age",
+                FieldDefinition::new(FieldType::Integer)
+                    .nullable()
+                    .rule(ValidationRule::MinValue(0.0))
+                    .rule(ValidationRule::MaxValue(120.0)),
+            )
+    }
+
+    #[test]
+    fn exports_required_and_nullable_fields() {
+        let document = user_schema().to_json_schema();
+        let required = document["required"].as_array().unwrap();
+        assert!(required.contains(&json!("user_id")));
+        assert!(required.contains(&json!("email")));
+        assert!(!required.contains(&json!("age")));
+        assert_eq!(document["properties"]["age"]["type"], json!(["integer", "null"]));
+    }
+
+    #[test]
+    fn exports_rules_as_json_schema_keywords() {
+        let document = user_schema().to_json_schema();
+        assert_eq!(document["properties"]["email"]["pattern"], json!(r"^[^@]+@[^@]+$"));
+        assert_eq!(document["properties"]["age"]["minimum"], json!(0.0));
+        assert_eq!(document["properties"]["age"]["maximum"], json!(120.0));
+    }
+
+    #[test]
+    fn schema_round_trips_via_json_schema() {
+        let schema = user_schema();
+        let document = schema.to_json_schema();
+        let imported = Schema::from_json_schema(&document).unwrap();
+        assert_eq!(schema, imported);
+    }
+
+    #[test]
+    fn combinator_rules_round_trip_via_json_schema() {
+        let schema = Schema::new("account").field(
+            "code",
+            FieldDefinition::new(FieldType::String).required().rule(
+                ValidationRule::AllOf(vec![
+                    ValidationRule::MinLength(3),
+                    ValidationRule::MaxLength(5),
+                ]),
+            ),
+        );
+        let document = schema.to_json_schema();
+        let imported = Schema::from_json_schema(&document).unwrap();
+        assert_eq!(schema, imported);
+    }
+
+    #[test]
+    fn not_rule_round_trips_via_json_schema() {
+        let schema = Schema::new("account").field(
+            "username",
+            FieldDefinition::new(FieldType::String).required().rule(
+                ValidationRule::Not(Box::new(ValidationRule::Pattern("^admin$".to_string()))),
+            ),
+        );
+        let document = schema.to_json_schema();
+        let imported = Schema::from_json_schema(&document).unwrap();
+        assert_eq!(schema, imported);
+    }
+
+    #[test]
+    fn datetime_field_round_trips_via_json_schema() {
+        let schema = Schema::new("event").field(
+            "created_at",
+            FieldDefinition::new(FieldType::DateTime)
+                .required()
+                .rule(ValidationRule::DateTimeFormat(DateTimeFormat::Iso8601)),
+        );
+        let document = schema.to_json_schema();
+        let imported = Schema::from_json_schema(&document).unwrap();
+        assert_eq!(schema, imported);
+    }
+
+    #[test]
+    fn from_json_schema_treats_a_missing_type_as_any() {
+        let document = json!({
+            "properties": { "name": {} }
+        });
+        let schema = Schema::from_json_schema(&document).unwrap();
+        assert_eq!(schema.fields["name"].field_type, FieldType::Any);
+    }
+
+    #[test]
+    fn from_json_schema_rejects_an_unsupported_type() {
+        let document = json!({
+            "properties": { "name": { "type": "widget" } }
+        });
+        assert!(matches!(
+            Schema::from_json_schema(&document),
+            Err(JsonSchemaError::UnsupportedType { .. })
+        ));
+    }
+}
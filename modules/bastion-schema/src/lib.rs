@@ -1,10 +1,16 @@
+pub mod codegen;
+pub mod compiled;
 pub mod error;
+pub mod infer;
+pub mod jsonschema;
 pub mod schema;
 pub mod types;
 pub mod validate;
 
 // Re-exports for ergonomic top-level usage
-pub use error::ValidationError;
+pub use compiled::CompiledSchema;
+pub use error::{CompileError, ValidationError};
+pub use jsonschema::JsonSchemaError;
 pub use schema::{FieldDefinition, Schema};
-pub use types::{DateTimeFormat, FieldType, ValidationRule};
+pub use types::{DateTimeFormat, FieldType, IpKind, Modifier, UnknownFields, ValidationRule};
 pub use validate::validate;
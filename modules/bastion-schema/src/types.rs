@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// The data type of a schema field.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 // `rename_all` to lowercase, so that the JSON representation is more concise and consistent with common conventions.
 // From the dashboard we want to send "string", "integer", etc. instead of "String", "Integer".
@@ -13,6 +14,28 @@ pub enum FieldType {
     DateTime,
     Object,
     Array,
+    /// Matches any JSON value. Used by `Schema::infer` when samples disagree
+    /// on a field's type and no single `FieldType` can represent all of them.
+    Any,
+}
+
+impl std::fmt::Display for FieldType {
+    /// Mirrors the `serde(rename_all = "lowercase")` wire form, so error
+    /// messages that embed a `FieldType` (e.g. `ValidationError::InvalidType`)
+    /// read the same "string"/"integer"/... vocabulary the dashboard sends.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            FieldType::String => "string",
+            FieldType::Integer => "integer",
+            FieldType::Float => "float",
+            FieldType::Boolean => "boolean",
+            FieldType::DateTime => "datetime",
+            FieldType::Object => "object",
+            FieldType::Array => "array",
+            FieldType::Any => "any",
+        };
+        f.write_str(s)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -22,6 +45,15 @@ pub enum DateTimeFormat {
     UnixTimestamp,
 }
 
+/// Which IP address family an `Ip` rule accepts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpKind {
+    V4,
+    V6,
+    Either,
+}
+
 /// A validation rule that can be applied to a field. It describes how to validate the field's value.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "rule", content = "value", rename_all = "snake_case")]
@@ -38,8 +70,69 @@ pub enum ValidationRule {
     MinValue(f64),
     /// Numeric maximum value (inclusive).
     MaxValue(f64),
+    /// Numeric minimum value (exclusive).
+    ExclusiveMinValue(f64),
+    /// Numeric maximum value (exclusive).
+    ExclusiveMaxValue(f64),
+    /// Value must equal one of the given values.
+    OneOf(Vec<Value>),
     /// DateTime format constraint. DateTime must match this format: "iso8601" | "unix_timestamp".
     DateTimeFormat(DateTimeFormat),
+    /// Value must satisfy every rule in the list.
+    AllOf(Vec<ValidationRule>),
+    /// Value must satisfy at least one rule in the list.
+    AnyOf(Vec<ValidationRule>),
+    /// Value must not satisfy the wrapped rule.
+    Not(Box<ValidationRule>),
+    /// String must be a valid email address (`local@domain`).
+    Email,
+    /// String must be a valid URL with a scheme and a host.
+    Url,
+    /// String must be a valid IP address, per the given family.
+    Ip(IpKind),
+    /// String must be a valid UUID (`8-4-4-4-12` hex groups).
+    Uuid,
+    /// String must be a valid credit card number (Luhn checksum).
+    CreditCard,
+}
+
+/// Controls how `validate` treats a payload key that is not declared in a
+/// `Schema`'s `fields`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownFields {
+    /// Undeclared keys are reported as an `UnknownField` error. Use this to
+    /// catch typos in event producers that a schema should be strict about.
+    Reject,
+    /// Undeclared keys are left untouched in the normalized payload. This is
+    /// the default, matching a schema that only describes part of the shape
+    /// it validates.
+    #[default]
+    Ignore,
+    /// Undeclared keys are moved out of the normalized object and regrouped
+    /// under a single `$unknown` object at that level, so callers can
+    /// inspect open-ended extension fields without them being mistaken for
+    /// declared ones.
+    Collect,
+}
+
+/// Normalizes a field's value before it is validated, mirroring the
+/// modify-then-validate pattern common in web-payload handling (e.g.
+/// trimming whitespace and lowercasing an email before a `Pattern` rule
+/// runs, or filling in a default when an optional field is absent).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "modifier", content = "value", rename_all = "snake_case")]
+pub enum Modifier {
+    /// Trims leading and trailing whitespace from a string value.
+    Trim,
+    /// Lowercases a string value.
+    Lowercase,
+    /// Uppercases a string value.
+    Uppercase,
+    /// Uppercases the first character of a string value and lowercases the rest.
+    Capitalize,
+    /// Fills in this value when the field is absent from the payload.
+    Default(Value),
 }
 
 #[cfg(test)]
@@ -60,6 +153,17 @@ mod tests {
         assert_eq!(json, r#"{"rule":"pattern","value":"^\\d+$"}"#);
     }
 
+    #[test]
+    fn any_field_type_serializes_lowercase() {
+        let json = serde_json::to_string(&FieldType::Any).unwrap();
+        assert_eq!(json, "\"any\"");
+    }
+
+    #[test]
+    fn field_type_displays_lowercase() {
+        assert_eq!(FieldType::DateTime.to_string(), "datetime");
+    }
+
     #[test]
     fn field_type_round_trips() {
         let original = FieldType::String;
@@ -74,4 +178,40 @@ mod tests {
         let json = serde_json::to_string(&rule).unwrap();
         assert_eq!(json, r#"{"rule":"date_time_format","value":"iso8601"}"#);
     }
+
+    #[test]
+    fn unit_format_rule_serializes_without_value() {
+        let rule = ValidationRule::Email;
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"rule":"email"}"#);
+    }
+
+    #[test]
+    fn ip_rule_serializes_with_kind() {
+        let rule = ValidationRule::Ip(IpKind::V4);
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"rule":"ip","value":"v4"}"#);
+    }
+
+    #[test]
+    fn unknown_fields_policy_serializes_snake_case() {
+        let json = serde_json::to_string(&UnknownFields::Collect).unwrap();
+        assert_eq!(json, "\"collect\"");
+    }
+
+    #[test]
+    fn unknown_fields_defaults_to_ignore() {
+        assert_eq!(UnknownFields::default(), UnknownFields::Ignore);
+    }
+
+    #[test]
+    fn combinator_rules_nest_naturally() {
+        let rule = ValidationRule::AllOf(vec![
+            ValidationRule::MinLength(3),
+            ValidationRule::Not(Box::new(ValidationRule::Pattern("^admin$".to_string()))),
+        ]);
+        let json = serde_json::to_string(&rule).unwrap();
+        let deserialized: ValidationRule = serde_json::from_str(&json).unwrap();
+        assert_eq!(rule, deserialized);
+    }
 }
@@ -1,71 +1,243 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::net::IpAddr;
+
 use regex::Regex;
 use serde_json::Value;
 
 use crate::error::ValidationError;
-use crate::schema::Schema;
-use crate::types::{DateTimeFormat, FieldType, ValidationRule};
+use crate::schema::{FieldDefinition, Schema};
+use crate::types::{DateTimeFormat, FieldType, IpKind, Modifier, UnknownFields, ValidationRule};
+
+/// Key under which `UnknownFields::Collect` regroups undeclared payload
+/// keys in the normalized object, at whatever nesting level they appear.
+const UNKNOWN_FIELDS_KEY: &str = "$unknown";
+
+/// Public API for validating a JSON payload against a schema. Returns the
+/// normalized payload (after applying field `modifiers`) if valid, or a
+/// list of validation errors if invalid.
+///
+/// This compiles any `Pattern` regex on the fly; for repeated validation
+/// against the same schema, prefer `Schema::compile` and
+/// `CompiledSchema::validate` instead.
+pub fn validate(schema: &Schema, payload: &Value) -> Result<Value, Vec<ValidationError>> {
+    validate_with_patterns(schema, payload, None)
+}
 
-/// Public API for validating a JSON payload against a schema. Returns Ok(()) if valid, or a list of validation errors if invalid.
-pub fn validate(schema: &Schema, payload: &Value) -> Result<(), Vec<ValidationError>> {
+/// Same as `validate`, but resolves `Pattern` rules through `patterns`
+/// instead of compiling them, when a precompiled regex is available for
+/// that pattern. Used by `CompiledSchema::validate`.
+pub(crate) fn validate_with_patterns(
+    schema: &Schema,
+    payload: &Value,
+    patterns: Option<&HashMap<String, Regex>>,
+) -> Result<Value, Vec<ValidationError>> {
     // payload is data coming from outside
 
     let mut errors: Vec<ValidationError> = vec![];
+    let normalized = validate_schema(schema, payload, "", patterns, &mut errors);
+
+    // Return the normalized payload, or all errors found
+    if errors.is_empty() {
+        Ok(normalized)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validates `payload` against `schema`, prefixing every error's field with
+/// `path` (a JSON-pointer-style path, e.g. `address` or `items/2`) so errors
+/// produced while recursing into nested objects/arrays stay unambiguous.
+/// Returns the normalized payload, with every field's `modifiers` applied.
+fn validate_schema(
+    schema: &Schema,
+    payload: &Value,
+    path: &str,
+    patterns: Option<&HashMap<String, Regex>>,
+    errors: &mut Vec<ValidationError>,
+) -> Value {
+    let mut normalized = payload.clone();
 
     // Per field in schema we need to validate the corresponding value in the payload
     // One iteration per field in the schema,
     // so that we can report all errors in one go instead of failing fast on the first error.
     for (field_name, definition) in &schema.fields {
-        // Get the value from the payload for this field, if it exists
-        let value = payload.get(field_name);
+        let field_path = join_path(path, field_name);
+        let value = validate_field(definition, payload.get(field_name).cloned(), &field_path, patterns, errors);
+        if let (Value::Object(map), Some(value)) = (&mut normalized, value) {
+            map.insert(field_name.clone(), value);
+        }
+    }
 
-        // Field is missing from the payload
-        if value.is_none() {
-            if definition.required {
-                errors.push(ValidationError::MissingField {
-                    field: field_name.clone(),
+    apply_unknown_fields_policy(schema, payload, path, &mut normalized, errors);
+
+    normalized
+}
+
+/// Enforces `schema.unknown_fields` on `payload`'s keys that aren't declared
+/// in `schema.fields`, mutating `normalized` in place for `Collect`.
+fn apply_unknown_fields_policy(
+    schema: &Schema,
+    payload: &Value,
+    path: &str,
+    normalized: &mut Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(payload) = payload.as_object() else {
+        return;
+    };
+    let unknown_keys: Vec<&String> = payload
+        .keys()
+        .filter(|key| !schema.fields.contains_key(key.as_str()))
+        .collect();
+    if unknown_keys.is_empty() {
+        return;
+    }
+
+    match schema.unknown_fields {
+        UnknownFields::Reject => {
+            for key in unknown_keys {
+                errors.push(ValidationError::UnknownField {
+                    field: join_path(path, key),
                 });
             }
-            // If type is not correct, does not make sense to apply rules,
-            // so we can skip to the next field
-            continue;
         }
+        UnknownFields::Ignore => {}
+        UnknownFields::Collect => {
+            if let Value::Object(map) = normalized {
+                let mut extra = serde_json::Map::new();
+                for key in unknown_keys {
+                    if let Some(value) = map.remove(key) {
+                        extra.insert(key.clone(), value);
+                    }
+                }
+                map.insert(UNKNOWN_FIELDS_KEY.to_string(), Value::Object(extra));
+            }
+        }
+    }
+}
 
-        // We already know the value is not None,
-        // so we can safely unwrap it for the rest of the checks
-        let value = value.unwrap();
+/// Validates a single value (a schema field, or an array element) against
+/// its `FieldDefinition`, recursing into `properties`/`items` when present.
+/// Returns the normalized value to store for this field, or `None` if it is
+/// absent and has no `Default` modifier to fill it in.
+fn validate_field(
+    definition: &FieldDefinition,
+    value: Option<Value>,
+    path: &str,
+    patterns: Option<&HashMap<String, Regex>>,
+    errors: &mut Vec<ValidationError>,
+) -> Option<Value> {
+    let value = definition
+        .modifiers
+        .iter()
+        .fold(value, |value, modifier| apply_modifier(modifier, value));
 
-        // Field is present but null
-        if value.is_null() {
-            if !definition.nullable {
-                errors.push(ValidationError::NullValue {
-                    field: field_name.clone(),
+    // Field is missing from the payload
+    let value = match value {
+        None => {
+            if definition.required {
+                errors.push(ValidationError::MissingField {
+                    field: path.to_string(),
                 });
             }
-            // If value is null, does not make sense to apply rules,
+            // If the value is missing, does not make sense to apply rules,
             // so we can skip to the next field
-            continue;
+            return None;
         }
+        Some(value) => value,
+    };
+
+    // Field is present but null
+    if value.is_null() {
+        if !definition.nullable {
+            errors.push(ValidationError::NullValue {
+                field: path.to_string(),
+            });
+        }
+        // If value is null, does not make sense to apply rules,
+        // so we can skip to the next field
+        return Some(value);
+    }
 
-        // Type check
-        if let Some(err) = check_type(field_name, &definition.field_type, value) {
+    // Type check
+    if let Some(err) = check_type(path, &definition.field_type, &value) {
+        errors.push(err);
+        return Some(value); // no point applying rules if the type is wrong
+    }
+
+    // Validation rules
+    // For each rule defined for this field, check if the value satisfies the rule. If not, add an error to the list.
+    for rule in &definition.rules {
+        if let Some(err) = check_rule(path, rule, &value, patterns) {
             errors.push(err);
-            continue; // no point applying rules if the type is wrong
         }
+    }
 
-        // Validation rules
-        // For each rule defined for this field, check if the value satisfies the rule. If not, add an error to the list.
-        for rule in &definition.rules {
-            if let Some(err) = check_rule(field_name, rule, value) {
-                errors.push(err);
-            }
+    // Recurse into nested objects/arrays, if the schema describes their shape
+    let value = match &definition.field_type {
+        FieldType::Object => match &definition.properties {
+            Some(properties) => validate_schema(properties, &value, path, patterns, errors),
+            None => value,
+        },
+        FieldType::Array => match (&definition.items, &value) {
+            (Some(items), Value::Array(elements)) => Value::Array(
+                elements
+                    .iter()
+                    .enumerate()
+                    .map(|(index, element)| {
+                        let element_path = join_path(path, &index.to_string());
+                        validate_field(items, Some(element.clone()), &element_path, patterns, errors)
+                            .unwrap_or(Value::Null)
+                    })
+                    .collect(),
+            ),
+            _ => value,
+        },
+        _ => value,
+    };
+
+    Some(value)
+}
+
+/// Applies a single `Modifier` to a field's value before it is validated.
+/// `Default` fills in a missing value; the rest transform a present string
+/// value and leave anything else (including a still-missing value)
+/// untouched.
+fn apply_modifier(modifier: &Modifier, value: Option<Value>) -> Option<Value> {
+    match modifier {
+        Modifier::Default(default) => value.or_else(|| Some(default.clone())),
+        Modifier::Trim => map_string(value, |s| s.trim().to_string()),
+        Modifier::Lowercase => map_string(value, |s| s.to_lowercase()),
+        Modifier::Uppercase => map_string(value, |s| s.to_uppercase()),
+        Modifier::Capitalize => map_string(value, capitalize),
+    }
+}
+
+fn map_string(value: Option<Value>, f: impl FnOnce(&str) -> String) -> Option<Value> {
+    match value {
+        Some(Value::String(s)) => Some(Value::String(f(&s))),
+        other => other,
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => {
+            let rest = chars.as_str().to_lowercase();
+            first.to_uppercase().chain(rest.chars()).collect()
         }
+        None => String::new(),
     }
+}
 
-    // Return all errors found, or Ok if no errors
-    if errors.is_empty() {
-        Ok(())
+/// Appends a path segment using the crate's JSON-pointer-style convention.
+fn join_path(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_string()
     } else {
-        Err(errors)
+        format!("{path}/{segment}")
     }
 }
 
@@ -90,6 +262,7 @@ fn check_type(field: &str, expected: &FieldType, value: &Value) -> Option<Valida
         FieldType::Array => value.is_array(),
         FieldType::Object => value.is_object(),
         FieldType::DateTime => value.is_string(), // format validated via rule
+        FieldType::Any => true,
     };
 
     if matches {
@@ -103,13 +276,25 @@ fn check_type(field: &str, expected: &FieldType, value: &Value) -> Option<Valida
     }
 }
 
-fn check_rule(field: &str, rule: &ValidationRule, value: &Value) -> Option<ValidationError> {
+fn check_rule(
+    field: &str,
+    rule: &ValidationRule,
+    value: &Value,
+    patterns: Option<&HashMap<String, Regex>>,
+) -> Option<ValidationError> {
     match rule {
         ValidationRule::Pattern(pattern) => {
             let s = value.as_str().unwrap_or("");
-            // It compiles the regex in every call, which is inefficient.
-            // Move to: lazy_static or OnceLock.
-            let re = Regex::new(pattern).ok()?;
+            // Prefer a precompiled regex from `Schema::compile`; fall back
+            // to compiling on the fly for the free-function `validate`.
+            let on_the_fly;
+            let re: &Regex = match patterns.and_then(|p| p.get(pattern)) {
+                Some(re) => re,
+                None => {
+                    on_the_fly = Regex::new(pattern).ok()?;
+                    &on_the_fly
+                }
+            };
             if !re.is_match(s) {
                 Some(ValidationError::RuleViolation {
                     field: field.to_string(),
@@ -148,12 +333,15 @@ fn check_rule(field: &str, rule: &ValidationRule, value: &Value) -> Option<Valid
         }
 
         ValidationRule::MinValue(min) => {
-            let n = value.as_f64().unwrap_or(f64::MAX);
-            if n < *min {
+            let below_min = match value {
+                Value::Number(n) => num_cmp(n, *min) == Ordering::Less,
+                _ => false,
+            };
+            if below_min {
                 Some(ValidationError::RuleViolation {
                     field: field.to_string(),
                     rule: rule.clone(),
-                    message: format!("value {} is less than minimum {}", n, min),
+                    message: format!("value {} is less than minimum {}", value, min),
                 })
             } else {
                 None
@@ -161,18 +349,70 @@ fn check_rule(field: &str, rule: &ValidationRule, value: &Value) -> Option<Valid
         }
 
         ValidationRule::MaxValue(max) => {
-            let n = value.as_f64().unwrap_or(f64::MIN);
-            if n > *max {
+            let above_max = match value {
+                Value::Number(n) => num_cmp(n, *max) == Ordering::Greater,
+                _ => false,
+            };
+            if above_max {
                 Some(ValidationError::RuleViolation {
                     field: field.to_string(),
                     rule: rule.clone(),
-                    message: format!("value {} exceeds maximum {}", n, max),
+                    message: format!("value {} exceeds maximum {}", value, max),
                 })
             } else {
                 None
             }
         }
 
+        ValidationRule::ExclusiveMinValue(min) => {
+            let at_or_below_min = match value {
+                Value::Number(n) => num_cmp(n, *min) != Ordering::Greater,
+                _ => false,
+            };
+            if at_or_below_min {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value {} is not strictly greater than {}", value, min),
+                })
+            } else {
+                None
+            }
+        }
+
+        ValidationRule::ExclusiveMaxValue(max) => {
+            let at_or_above_max = match value {
+                Value::Number(n) => num_cmp(n, *max) != Ordering::Less,
+                _ => false,
+            };
+            if at_or_above_max {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value {} is not strictly less than {}", value, max),
+                })
+            } else {
+                None
+            }
+        }
+
+        ValidationRule::OneOf(allowed) => {
+            if allowed.contains(value) {
+                None
+            } else {
+                let allowed_str = allowed
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value {} is not one of: {}", value, allowed_str),
+                })
+            }
+        }
+
         ValidationRule::DateTimeFormat(format) => {
             let s = value.as_str().unwrap_or("");
             let valid = match format {
@@ -189,6 +429,230 @@ fn check_rule(field: &str, rule: &ValidationRule, value: &Value) -> Option<Valid
                 None
             }
         }
+
+        ValidationRule::Email => {
+            let s = value.as_str().unwrap_or("");
+            if is_valid_email(s) {
+                None
+            } else {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value '{}' is not a valid email address", s),
+                })
+            }
+        }
+
+        ValidationRule::Url => {
+            let s = value.as_str().unwrap_or("");
+            if is_valid_url(s) {
+                None
+            } else {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value '{}' is not a valid URL", s),
+                })
+            }
+        }
+
+        ValidationRule::Ip(kind) => {
+            let s = value.as_str().unwrap_or("");
+            let valid = match s.parse::<IpAddr>() {
+                Ok(IpAddr::V4(_)) => matches!(kind, IpKind::V4 | IpKind::Either),
+                Ok(IpAddr::V6(_)) => matches!(kind, IpKind::V6 | IpKind::Either),
+                Err(_) => false,
+            };
+            if valid {
+                None
+            } else {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value '{}' is not a valid {:?} address", s, kind),
+                })
+            }
+        }
+
+        ValidationRule::Uuid => {
+            let s = value.as_str().unwrap_or("");
+            if is_valid_uuid(s) {
+                None
+            } else {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value '{}' is not a valid UUID", s),
+                })
+            }
+        }
+
+        ValidationRule::CreditCard => {
+            let s = value.as_str().unwrap_or("");
+            if is_valid_credit_card(s) {
+                None
+            } else {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value '{}' is not a valid credit card number", s),
+                })
+            }
+        }
+
+        ValidationRule::AllOf(rules) => {
+            let messages: Vec<String> = rules
+                .iter()
+                .filter_map(|r| check_rule(field, r, value, patterns))
+                .map(|err| violation_message(&err))
+                .collect();
+            if messages.is_empty() {
+                None
+            } else {
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("failed all_of: {}", messages.join("; ")),
+                })
+            }
+        }
+
+        ValidationRule::AnyOf(rules) => {
+            let mut messages = vec![];
+            for r in rules {
+                match check_rule(field, r, value, patterns) {
+                    None => return None,
+                    Some(err) => messages.push(violation_message(&err)),
+                }
+            }
+            Some(ValidationError::RuleViolation {
+                field: field.to_string(),
+                rule: rule.clone(),
+                message: format!("matched none of: {}", messages.join("; ")),
+            })
+        }
+
+        ValidationRule::Not(inner) => {
+            if check_rule(field, inner, value, patterns).is_none() {
+                // The wrapped rule succeeded, which is exactly what `Not` forbids.
+                Some(ValidationError::RuleViolation {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    message: format!("value must not satisfy rule {:?}", inner),
+                })
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Minimal `local@domain` check: non-empty local part, and a domain made
+/// of non-empty dot-separated labels.
+fn is_valid_email(s: &str) -> bool {
+    let Some((local, domain)) = s.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+/// Minimal `scheme://host` check: a non-empty scheme of ASCII letters,
+/// digits, `+`, `-` or `.`, and a non-empty host.
+fn is_valid_url(s: &str) -> bool {
+    let Some((scheme, rest)) = s.split_once("://") else {
+        return false;
+    };
+    let valid_scheme = !scheme.is_empty()
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    valid_scheme && !host.is_empty()
+}
+
+/// A UUID is five hyphen-separated hex groups of length 8-4-4-4-12.
+fn is_valid_uuid(s: &str) -> bool {
+    const GROUP_LENGTHS: [usize; 5] = [8, 4, 4, 4, 12];
+    let groups: Vec<&str> = s.split('-').collect();
+    groups.len() == GROUP_LENGTHS.len()
+        && groups
+            .iter()
+            .zip(GROUP_LENGTHS)
+            .all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Luhn checksum over the digits of `s`, ignoring any other characters.
+fn is_valid_credit_card(s: &str) -> bool {
+    let digits: Vec<u32> = s.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() < 2 {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| {
+            if i % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Extracts the human-readable message from a rule-check error, falling
+/// back to its `Display` form for non-`RuleViolation` variants.
+fn violation_message(err: &ValidationError) -> String {
+    match err {
+        ValidationError::RuleViolation { message, .. } => message.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Compares a JSON number against an `f64` bound without losing precision
+/// for integers that fall outside the range f64 can represent exactly
+/// (beyond 2^53), e.g. large `user_id`s or millisecond timestamps stored
+/// as i64/u64.
+fn num_cmp(value: &serde_json::Number, limit: f64) -> Ordering {
+    if let Some(u) = value.as_u64() {
+        return int_cmp(u as i128, limit);
+    }
+    if let Some(i) = value.as_i64() {
+        return int_cmp(i as i128, limit);
+    }
+    // Genuine f64 value: fall back to partial comparison. NaN has no
+    // defined ordering, so it is treated as failing the bound check.
+    value
+        .as_f64()
+        .unwrap_or(f64::NAN)
+        .partial_cmp(&limit)
+        .unwrap_or(Ordering::Less)
+}
+
+/// Compares an exact integer against a floating-point bound, keeping the
+/// integer side exact instead of round-tripping it through f64.
+fn int_cmp(n: i128, limit: f64) -> Ordering {
+    const I128_MIN_F64: f64 = i128::MIN as f64;
+    const I128_MAX_F64: f64 = i128::MAX as f64;
+
+    if limit > I128_MAX_F64 {
+        return Ordering::Less;
+    }
+    if limit < I128_MIN_F64 {
+        return Ordering::Greater;
+    }
+
+    if limit.fract() == 0.0 {
+        n.cmp(&(limit as i128))
+    } else if n <= limit.floor() as i128 {
+        Ordering::Less
+    } else {
+        Ordering::Greater
     }
 }
 
@@ -196,7 +660,7 @@ fn check_rule(field: &str, rule: &ValidationRule, value: &Value) -> Option<Valid
 mod tests {
     use super::*;
     use crate::schema::{FieldDefinition, Schema};
-    use crate::types::{DateTimeFormat, FieldType, ValidationRule};
+    use crate::types::{DateTimeFormat, FieldType, UnknownFields, ValidationRule};
     use serde_json::json;
 
     // ── Helpers ─────────────────────────────────────────────────────────────
@@ -442,6 +906,29 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn large_integer_respects_exact_bound() {
+        // 2^53 + 1 (ts) is not exactly representable as an f64 and would be
+        // rounded by the compiler if used as a limit literal, so the bound
+        // uses its nearest representable neighbor, 2^53 + 2, instead. A
+        // naive as_f64() round-trip of `ts` collapses it onto a neighboring
+        // representable float and could misjudge which side of the bound it
+        // falls on; comparing as exact integers does not.
+        let schema = Schema::new("event").field(
+            "ts",
+            FieldDefinition::new(FieldType::Integer)
+                .required()
+                .rule(ValidationRule::MinValue(9_007_199_254_740_994.0)),
+        );
+        let payload = json!({ "ts": 9_007_199_254_740_993i64 });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::RuleViolation { field, rule: ValidationRule::MinValue(_), .. }
+            if field == "ts"
+        )));
+    }
+
     // ── DateTime ─────────────────────────────────────────────────────────────
 
     #[test]
@@ -499,4 +986,448 @@ mod tests {
         let errors = validate(&schema, &payload).unwrap_err();
         assert!(errors.len() >= 4); // MissingField + Pattern + MinLength + MaxValue
     }
+
+    // ── Nested objects / arrays ──────────────────────────────────────────────
+
+    #[test]
+    fn nested_object_field_is_validated_with_pointer_path() {
+        let schema = Schema::new("user").field(
+            "address",
+            FieldDefinition::new(FieldType::Object).required().properties(
+                Schema::new("address").field(
+                    "zip",
+                    FieldDefinition::new(FieldType::String)
+                        .required()
+                        .rule(ValidationRule::MinLength(5)),
+                ),
+            ),
+        );
+        let payload = json!({ "address": { "zip": "123" } });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::RuleViolation { field, .. } if field == "address/zip"
+        )));
+    }
+
+    #[test]
+    fn nested_array_items_are_validated_with_index_path() {
+        let schema = Schema::new("user").field(
+            "tags",
+            FieldDefinition::new(FieldType::Array)
+                .required()
+                .items(FieldDefinition::new(FieldType::String).rule(ValidationRule::MinLength(2))),
+        );
+        let payload = json!({ "tags": ["ok", "a"] });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::RuleViolation { field, .. } if field == "tags/1"
+        )));
+    }
+
+    #[test]
+    fn required_and_nullable_propagate_through_multiple_nesting_levels() {
+        // A schema three levels deep: order -> items[] -> price (required, non-nullable).
+        // `required`/`nullable` must be enforced at every level it's declared on,
+        // not just the outermost one.
+        let schema = Schema::new("order").field(
+            "items",
+            FieldDefinition::new(FieldType::Array).required().items(
+                FieldDefinition::new(FieldType::Object).required().properties(
+                    Schema::new("item").field(
+                        "price",
+                        FieldDefinition::new(FieldType::Float).required(),
+                    ),
+                ),
+            ),
+        );
+
+        // Missing price on the second element is reported at its own path.
+        let payload = json!({ "items": [{ "price": 9.99 }, {}] });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::MissingField { field } if field == "items/1/price"
+        )));
+
+        // A null price on the first element is reported at its own path.
+        let payload = json!({ "items": [{ "price": null }] });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::NullValue { field } if field == "items/0/price"
+        )));
+
+        // Every level present and valid passes.
+        let payload = json!({ "items": [{ "price": 9.99 }, { "price": 4.5 }] });
+        assert!(validate(&schema, &payload).is_ok());
+    }
+
+    #[test]
+    fn valid_nested_payload_passes() {
+        let schema = Schema::new("user").field(
+            "address",
+            FieldDefinition::new(FieldType::Object).required().properties(
+                Schema::new("address").field(
+                    "zip",
+                    FieldDefinition::new(FieldType::String).required(),
+                ),
+            ),
+        );
+        let payload = json!({ "address": { "zip": "12345" } });
+        assert!(validate(&schema, &payload).is_ok());
+    }
+
+    // ── Exclusive bounds / OneOf ─────────────────────────────────────────────
+
+    #[test]
+    fn exclusive_min_rejects_the_boundary_value() {
+        let schema = Schema::new("event").field(
+            "score",
+            FieldDefinition::new(FieldType::Integer)
+                .required()
+                .rule(ValidationRule::ExclusiveMinValue(0.0)),
+        );
+        assert!(validate(&schema, &json!({ "score": 0 })).is_err());
+        assert!(validate(&schema, &json!({ "score": 1 })).is_ok());
+    }
+
+    #[test]
+    fn exclusive_max_rejects_the_boundary_value() {
+        let schema = Schema::new("event").field(
+            "score",
+            FieldDefinition::new(FieldType::Integer)
+                .required()
+                .rule(ValidationRule::ExclusiveMaxValue(100.0)),
+        );
+        assert!(validate(&schema, &json!({ "score": 100 })).is_err());
+        assert!(validate(&schema, &json!({ "score": 99 })).is_ok());
+    }
+
+    #[test]
+    fn one_of_accepts_only_listed_values() {
+        let schema = Schema::new("account").field(
+            "status",
+            FieldDefinition::new(FieldType::String).required().rule(
+                ValidationRule::OneOf(vec![
+                    json!("active"),
+                    json!("suspended"),
+                    json!("closed"),
+                ]),
+            ),
+        );
+        assert!(validate(&schema, &json!({ "status": "suspended" })).is_ok());
+        let errors = validate(&schema, &json!({ "status": "unknown" })).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::RuleViolation { field, rule: ValidationRule::OneOf(_), .. }
+            if field == "status"
+        )));
+    }
+
+    // ── Semantic format rules ────────────────────────────────────────────────
+
+    #[test]
+    fn valid_email_passes() {
+        let schema = Schema::new("user").field(
+            "email",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::Email),
+        );
+        assert!(validate(&schema, &json!({ "email": "carlos@example.com" })).is_ok());
+        assert!(validate(&schema, &json!({ "email": "not-an-email" })).is_err());
+    }
+
+    #[test]
+    fn valid_url_passes() {
+        let schema = Schema::new("site").field(
+            "homepage",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::Url),
+        );
+        assert!(validate(&schema, &json!({ "homepage": "https://example.com/path" })).is_ok());
+        assert!(validate(&schema, &json!({ "homepage": "not a url" })).is_err());
+    }
+
+    #[test]
+    fn ip_rule_respects_requested_family() {
+        let schema = Schema::new("host").field(
+            "addr",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::Ip(IpKind::V4)),
+        );
+        assert!(validate(&schema, &json!({ "addr": "127.0.0.1" })).is_ok());
+        assert!(validate(&schema, &json!({ "addr": "::1" })).is_err());
+    }
+
+    #[test]
+    fn valid_uuid_passes() {
+        let schema = Schema::new("entity").field(
+            "id",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::Uuid),
+        );
+        assert!(validate(&schema, &json!({ "id": "550e8400-e29b-41d4-a716-446655440000" })).is_ok());
+        assert!(validate(&schema, &json!({ "id": "not-a-uuid" })).is_err());
+    }
+
+    #[test]
+    fn valid_credit_card_passes_luhn() {
+        let schema = Schema::new("payment").field(
+            "card_number",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::CreditCard),
+        );
+        assert!(validate(&schema, &json!({ "card_number": "4111 1111 1111 1111" })).is_ok());
+        assert!(validate(&schema, &json!({ "card_number": "4111 1111 1111 1112" })).is_err());
+    }
+
+    // ── AllOf / AnyOf / Not ──────────────────────────────────────────────────
+
+    #[test]
+    fn all_of_passes_when_every_child_passes() {
+        let schema = Schema::new("event").field(
+            "code",
+            FieldDefinition::new(FieldType::String).required().rule(
+                ValidationRule::AllOf(vec![
+                    ValidationRule::MinLength(3),
+                    ValidationRule::MaxLength(5),
+                ]),
+            ),
+        );
+        let payload = json!({ "code": "abcd" });
+        assert!(validate(&schema, &payload).is_ok());
+    }
+
+    #[test]
+    fn all_of_fails_when_any_child_fails() {
+        let schema = Schema::new("event").field(
+            "code",
+            FieldDefinition::new(FieldType::String).required().rule(
+                ValidationRule::AllOf(vec![
+                    ValidationRule::MinLength(3),
+                    ValidationRule::MaxLength(5),
+                ]),
+            ),
+        );
+        let payload = json!({ "code": "ab" });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(
+            |e| matches!(e, ValidationError::RuleViolation { field, rule: ValidationRule::AllOf(_), .. } if field == "code")
+        ));
+    }
+
+    #[test]
+    fn any_of_passes_when_one_child_passes() {
+        let schema = Schema::new("event").field(
+            "status",
+            FieldDefinition::new(FieldType::String).required().rule(
+                ValidationRule::AnyOf(vec![
+                    ValidationRule::Pattern("^active$".to_string()),
+                    ValidationRule::Pattern("^closed$".to_string()),
+                ]),
+            ),
+        );
+        let payload = json!({ "status": "closed" });
+        assert!(validate(&schema, &payload).is_ok());
+    }
+
+    #[test]
+    fn any_of_fails_when_no_child_passes() {
+        let schema = Schema::new("event").field(
+            "status",
+            FieldDefinition::new(FieldType::String).required().rule(
+                ValidationRule::AnyOf(vec![
+                    ValidationRule::Pattern("^active$".to_string()),
+                    ValidationRule::Pattern("^closed$".to_string()),
+                ]),
+            ),
+        );
+        let payload = json!({ "status": "pending" });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(
+            |e| matches!(e, ValidationError::RuleViolation { field, rule: ValidationRule::AnyOf(_), .. } if field == "status")
+        ));
+    }
+
+    #[test]
+    fn not_fails_when_wrapped_rule_passes() {
+        let schema = Schema::new("event").field(
+            "username",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::Not(Box::new(ValidationRule::Pattern(
+                    "^admin$".to_string(),
+                )))),
+        );
+        let payload = json!({ "username": "admin" });
+        let errors = validate(&schema, &payload).unwrap_err();
+        assert!(errors.iter().any(
+            |e| matches!(e, ValidationError::RuleViolation { field, rule: ValidationRule::Not(_), .. } if field == "username")
+        ));
+    }
+
+    #[test]
+    fn not_passes_when_wrapped_rule_fails() {
+        let schema = Schema::new("event").field(
+            "username",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .rule(ValidationRule::Not(Box::new(ValidationRule::Pattern(
+                    "^admin$".to_string(),
+                )))),
+        );
+        let payload = json!({ "username": "carlos" });
+        assert!(validate(&schema, &payload).is_ok());
+    }
+
+    // ── num_cmp ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn num_cmp_compares_large_u64_exactly() {
+        // `u64::MAX - 1` rounds to the same f64 as `u64::MAX` at this
+        // magnitude, so the limit below is instead a round number with
+        // enough margin below `u64::MAX` to survive f64 rounding.
+        let n = serde_json::Number::from(u64::MAX);
+        assert_eq!(num_cmp(&n, 18_000_000_000_000_000_000.0), Ordering::Greater);
+    }
+
+    #[test]
+    fn num_cmp_handles_fractional_limit() {
+        let n = serde_json::Number::from(3);
+        assert_eq!(num_cmp(&n, 2.5), Ordering::Greater);
+        assert_eq!(num_cmp(&n, 3.5), Ordering::Less);
+    }
+
+    #[test]
+    fn num_cmp_falls_back_to_f64_for_float_values() {
+        let n = serde_json::Number::from_f64(1.5).unwrap();
+        assert_eq!(num_cmp(&n, 1.0), Ordering::Greater);
+    }
+
+    // ── Modifiers ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn trim_modifier_strips_whitespace_before_rules_run() {
+        let schema = Schema::new("user").field(
+            "username",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .modifier(Modifier::Trim)
+                .rule(ValidationRule::MinLength(3)),
+        );
+        let normalized = validate(&schema, &json!({ "username": "  ab " })).unwrap_err();
+        assert!(normalized.iter().any(|e| matches!(
+            e,
+            ValidationError::RuleViolation { field, rule: ValidationRule::MinLength(_), .. }
+            if field == "username"
+        )));
+
+        let payload = json!({ "username": "  carlos  " });
+        let normalized = validate(&schema, &payload).unwrap();
+        assert_eq!(normalized["username"], json!("carlos"));
+    }
+
+    #[test]
+    fn lowercase_modifier_normalizes_before_pattern_rule_runs() {
+        let schema = Schema::new("user").field(
+            "email",
+            FieldDefinition::new(FieldType::String)
+                .required()
+                .modifier(Modifier::Lowercase)
+                .rule(ValidationRule::Pattern(r"^[^@]+@[^@]+$".to_string())),
+        );
+        let normalized = validate(&schema, &json!({ "email": "Carlos@Example.com" })).unwrap();
+        assert_eq!(normalized["email"], json!("carlos@example.com"));
+    }
+
+    #[test]
+    fn uppercase_modifier_transforms_string_value() {
+        let schema = Schema::new("entity").field(
+            "code",
+            FieldDefinition::new(FieldType::String).required().modifier(Modifier::Uppercase),
+        );
+        let normalized = validate(&schema, &json!({ "code": "ab-12" })).unwrap();
+        assert_eq!(normalized["code"], json!("AB-12"));
+    }
+
+    #[test]
+    fn capitalize_modifier_uppercases_only_the_first_character() {
+        let schema = Schema::new("entity").field(
+            "name",
+            FieldDefinition::new(FieldType::String).required().modifier(Modifier::Capitalize),
+        );
+        let normalized = validate(&schema, &json!({ "name": "carlos" })).unwrap();
+        assert_eq!(normalized["name"], json!("Carlos"));
+    }
+
+    #[test]
+    fn default_modifier_fills_in_a_missing_field() {
+        let schema = Schema::new("account").field(
+            "status",
+            FieldDefinition::new(FieldType::String).modifier(Modifier::Default(json!("active"))),
+        );
+        let normalized = validate(&schema, &json!({})).unwrap();
+        assert_eq!(normalized["status"], json!("active"));
+    }
+
+    #[test]
+    fn default_modifier_does_not_override_a_present_value() {
+        let schema = Schema::new("account").field(
+            "status",
+            FieldDefinition::new(FieldType::String).modifier(Modifier::Default(json!("active"))),
+        );
+        let normalized = validate(&schema, &json!({ "status": "suspended" })).unwrap();
+        assert_eq!(normalized["status"], json!("suspended"));
+    }
+
+    // ── Unknown fields policy ────────────────────────────────────────────────
+
+    #[test]
+    fn ignore_policy_passes_unknown_fields_through_by_default() {
+        let schema = Schema::new("event").field("id", FieldDefinition::new(FieldType::Integer).required());
+        let normalized = validate(&schema, &json!({ "id": 1, "nickname": "cj" })).unwrap();
+        assert_eq!(normalized["nickname"], json!("cj"));
+    }
+
+    #[test]
+    fn reject_policy_reports_every_unknown_field() {
+        let schema = Schema::new("event")
+            .field("id", FieldDefinition::new(FieldType::Integer).required())
+            .unknown_fields(UnknownFields::Reject);
+        let errors = validate(&schema, &json!({ "id": 1, "nickname": "cj" })).unwrap_err();
+        assert!(errors.iter().any(
+            |e| matches!(e, ValidationError::UnknownField { field } if field == "nickname")
+        ));
+    }
+
+    #[test]
+    fn collect_policy_regroups_unknown_fields() {
+        let schema = Schema::new("event")
+            .field("id", FieldDefinition::new(FieldType::Integer).required())
+            .unknown_fields(UnknownFields::Collect);
+        let normalized = validate(&schema, &json!({ "id": 1, "nickname": "cj" })).unwrap();
+        assert_eq!(normalized["id"], json!(1));
+        assert_eq!(normalized["$unknown"], json!({ "nickname": "cj" }));
+        assert!(normalized.get("nickname").is_none());
+    }
+
+    #[test]
+    fn valid_payload_returns_the_normalized_value() {
+        let schema = user_schema();
+        let payload = json!({
+            "user_id": 1,
+            "email": "carlos@example.com",
+            "username": "carlos",
+            "age": 30
+        });
+        let normalized = validate(&schema, &payload).unwrap();
+        assert_eq!(normalized, payload);
+    }
 }